@@ -0,0 +1,73 @@
+use {
+    num_derive::FromPrimitive,
+    solana_program::{decode_error::DecodeError, program_error::ProgramError},
+    thiserror::Error,
+};
+
+/// Errors that may be returned by the Metaplex program.
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum MetaplexError {
+    /// Numerical overflow error
+    #[error("Numerical overflow error")]
+    NumericalOverflowError,
+
+    /// Provided account does not contain exactly one token
+    #[error("Provided account does not contain one token")]
+    ProvidedAccountDoesNotContainOneToken,
+
+    /// This bid endpoint does not match the prize type of the winning config it was called on
+    #[error("This bid endpoint was not for this prize type")]
+    WrongBidEndpointForPrize,
+
+    /// Edition number given is invalid for this winning config item
+    #[error("Edition number given is invalid for this winning config item")]
+    InvalidEditionNumber,
+
+    /// Tried to read an account as one `Key`-tagged type but its tag didn't match any
+    /// version this program knows how to parse
+    #[error("Data type mismatch")]
+    DataTypeMismatch,
+
+    /// The collection mint supplied by the caller doesn't match the mint the collection's
+    /// master edition metadata declares itself to be
+    #[error("Collection mint does not match the collection master edition's declared mint")]
+    CollectionMintMismatch,
+
+    /// The auction manager is neither the collection's update authority nor the holder of a
+    /// valid `CollectionAuthorityRecord` delegating that authority to it
+    #[error("Auction manager is not an authorized collection authority")]
+    CollectionAuthorityNotDelegated,
+
+    /// A `UsesConfigArgs` failed validation: either `total` was zero, or `use_method` was
+    /// `Single` with a `total` other than one
+    #[error("Invalid use configuration")]
+    InvalidUseConfig,
+
+    /// The auction manager must be the update authority of the edition's metadata before
+    /// `update_metadata_accounts_v2` can be signed for it to set the edition's `Uses`
+    #[error("Auction manager is not the update authority of this metadata")]
+    AuctionManagerNotUpdateAuthority,
+
+    /// Redemption requires a prize tracking ticket that `ValidatePrintingV2Prize` already
+    /// created; redeeming never creates one itself, so whoever redeems first can't pick the
+    /// edition supply snapshot
+    #[error("Prize must be validated with ValidatePrintingV2Prize before it can be redeemed")]
+    PrizeNotValidated,
+
+    /// `collection_mint_info`, `collection_metadata_info` and `collection_master_edition_info`
+    /// must be supplied together or not at all
+    #[error("Collection accounts must be supplied together or not at all")]
+    IncompleteCollectionAccounts,
+}
+
+impl From<MetaplexError> for ProgramError {
+    fn from(e: MetaplexError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl<T> DecodeError<T> for MetaplexError {
+    fn type_of() -> &'static str {
+        "Metaplex Error"
+    }
+}
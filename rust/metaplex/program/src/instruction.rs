@@ -0,0 +1,46 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Instructions supported by the Metaplex program.
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+pub enum MetaplexInstruction {
+    /// Redeem a single PrintingV2 edition from a winning bid.
+    ///
+    ///   0. `[writable]` Auction manager
+    ///   ... see `processor::redeem_printing_v2_bid::process_redeem_printing_v2_bid` for the
+    ///       full account list, including the optional trailing collection accounts.
+    RedeemPrintingV2Bid {
+        edition_offset: u64,
+        win_index: u64,
+    },
+
+    /// Redeem `count` consecutive PrintingV2 editions from a single winning config item in one
+    /// instruction, instead of one `RedeemPrintingV2Bid` per edition.
+    ///
+    ///   0. `[writable]` Auction manager
+    ///   ... see
+    ///       `processor::redeem_printing_v2_bid::process_redeem_printing_v2_bid_batch` for the
+    ///       full account list, including the per-edition account groups and edition markers
+    ///       that follow the fixed accounts.
+    RedeemPrintingV2BidBatch {
+        edition_offset_start: u64,
+        count: u64,
+        win_index: u64,
+    },
+
+    /// Snapshots a PrintingV2 prize's master edition supply once, ahead of any redemption, and
+    /// locks it into that prize's tracking ticket. Must run when the safety deposit box backing
+    /// the prize is validated into the auction, before bidding opens, so that `edition_offset`s
+    /// passed to `RedeemPrintingV2Bid[Batch]` always resolve to the same `actual_edition`
+    /// regardless of which winner — of this prize or of another one sharing the same metadata —
+    /// happens to redeem first.
+    ///
+    ///   0. `[]` Auction manager
+    ///   1. `[]` Metadata
+    ///   2. `[]` Master edition
+    ///   3. `[writable]` Prize tracking ticket (PDA seeds: `['metaplex', program_id,
+    ///      auction_manager, metadata.mint]`)
+    ///   4. `[writable, signer]` Payer
+    ///   5. `[]` Rent sysvar
+    ///   6. `[]` System program
+    ValidatePrintingV2Prize { expected_redemptions: u64 },
+}
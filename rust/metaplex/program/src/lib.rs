@@ -0,0 +1,14 @@
+pub mod error;
+pub mod instruction;
+pub mod processor;
+pub mod state;
+pub mod utils;
+
+solana_program::entrypoint!(process_instruction);
+fn process_instruction<'a>(
+    program_id: &'a solana_program::pubkey::Pubkey,
+    accounts: &'a [solana_program::account_info::AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> solana_program::entrypoint::ProgramResult {
+    processor::process_instruction(program_id, accounts, instruction_data)
+}
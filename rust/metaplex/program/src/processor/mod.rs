@@ -0,0 +1,40 @@
+pub mod redeem_printing_v2_bid;
+
+use {
+    crate::instruction::MetaplexInstruction,
+    borsh::BorshDeserialize,
+    redeem_printing_v2_bid::{
+        process_redeem_printing_v2_bid, process_redeem_printing_v2_bid_batch,
+        process_validate_printing_v2_prize,
+    },
+    solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey},
+};
+
+pub fn process_instruction<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    input: &[u8],
+) -> ProgramResult {
+    let instruction = MetaplexInstruction::try_from_slice(input)?;
+
+    match instruction {
+        MetaplexInstruction::RedeemPrintingV2Bid {
+            edition_offset,
+            win_index,
+        } => process_redeem_printing_v2_bid(program_id, accounts, edition_offset, win_index),
+        MetaplexInstruction::RedeemPrintingV2BidBatch {
+            edition_offset_start,
+            count,
+            win_index,
+        } => process_redeem_printing_v2_bid_batch(
+            program_id,
+            accounts,
+            edition_offset_start,
+            count,
+            win_index,
+        ),
+        MetaplexInstruction::ValidatePrintingV2Prize {
+            expected_redemptions,
+        } => process_validate_printing_v2_prize(program_id, accounts, expected_redemptions),
+    }
+}
@@ -2,7 +2,8 @@ use {
     crate::{
         error::MetaplexError,
         state::{
-            Key, WinningConfigItem, WinningConfigType, MAX_PRIZE_TRACKING_TICKET_SIZE, PREFIX,
+            Key, UsesConfigArgs, WinningConfigItem, WinningConfigType,
+            MAX_PRIZE_TRACKING_TICKET_SIZE, PREFIX,
         },
         utils::{
             assert_derivation, assert_is_ata, assert_owned_by, common_redeem_checks,
@@ -20,12 +21,208 @@ use {
         pubkey::Pubkey,
     },
     spl_token_metadata::{
-        instruction::mint_edition_from_master_edition_via_vault_proxy,
+        instruction::{
+            mint_edition_from_master_edition_via_vault_proxy, update_metadata_accounts_v2,
+            verify_collection,
+        },
+        state::{Metadata, UseMethod, Uses, EDITION_MARKER_BIT_SIZE},
         utils::get_supply_off_master_edition,
     },
     spl_token_vault::state::SafetyDepositBox,
+    std::collections::BTreeMap,
 };
 
+/// Optional accounts needed to verify the freshly minted edition as belonging to a
+/// collection. `collection_mint_info`, `collection_metadata_info` and
+/// `collection_master_edition_info` must be present together or not at all;
+/// `collection_authority_record_info` is additionally required unless the auction manager PDA
+/// is itself the collection's update authority. `process_redeem_printing_v2_bid`'s account
+/// parsing enforces the all-or-none rule; `assert_collection_verify_is_valid` enforces the
+/// record requirement.
+pub struct CollectionVerifyArgs<'a, 'b: 'a> {
+    pub collection_metadata_info: &'a AccountInfo<'b>,
+    pub collection_mint_info: &'a AccountInfo<'b>,
+    pub collection_master_edition_info: &'a AccountInfo<'b>,
+    pub collection_authority_record_info: Option<&'a AccountInfo<'b>>,
+}
+
+/// Mirrors token-metadata's `assert_collection_update_is_valid`: the collection mint supplied
+/// by the caller must be the same mint the collection's master edition metadata actually
+/// declares itself to be, and the auction manager PDA must be authorized over the collection —
+/// either because it *is* the collection metadata's update authority, or because it holds a
+/// `CollectionAuthorityRecord` the update authority delegated to it.
+fn assert_collection_verify_is_valid(
+    collection_metadata_info: &AccountInfo,
+    collection_mint_info: &AccountInfo,
+    collection_master_edition_info: &AccountInfo,
+    collection_authority_info: &AccountInfo,
+    collection_authority_record_info: Option<&AccountInfo>,
+    token_metadata_program: &Pubkey,
+) -> ProgramResult {
+    assert_owned_by(collection_metadata_info, token_metadata_program)?;
+    assert_owned_by(collection_master_edition_info, token_metadata_program)?;
+
+    let collection_metadata = Metadata::from_account_info(collection_metadata_info)?;
+
+    if collection_metadata.mint != *collection_mint_info.key {
+        return Err(MetaplexError::CollectionMintMismatch.into());
+    }
+
+    assert_derivation(
+        token_metadata_program,
+        collection_master_edition_info,
+        &[
+            spl_token_metadata::state::PREFIX.as_bytes(),
+            token_metadata_program.as_ref(),
+            collection_mint_info.key.as_ref(),
+            "edition".as_bytes(),
+        ],
+    )?;
+
+    if collection_metadata.update_authority == *collection_authority_info.key {
+        return Ok(());
+    }
+
+    let collection_authority_record_info = collection_authority_record_info
+        .ok_or(MetaplexError::CollectionAuthorityNotDelegated)?;
+
+    assert_owned_by(collection_authority_record_info, token_metadata_program)?;
+
+    let (expected_record, _bump) = Pubkey::find_program_address(
+        &[
+            spl_token_metadata::state::PREFIX.as_bytes(),
+            token_metadata_program.as_ref(),
+            collection_mint_info.key.as_ref(),
+            "collection_authority".as_bytes(),
+            collection_authority_info.key.as_ref(),
+        ],
+        token_metadata_program,
+    );
+
+    if expected_record != *collection_authority_record_info.key
+        || collection_authority_record_info.data_is_empty()
+    {
+        return Err(MetaplexError::CollectionAuthorityNotDelegated.into());
+    }
+
+    Ok(())
+}
+
+/// Mirrors token-metadata's `assert_valid_use`: a use count must be configured in full (you
+/// can't redeem a prize that's already half-consumed), and a `Single`-use item only ever makes
+/// sense with a total of exactly one use.
+fn assert_valid_use_config(uses_config: &UsesConfigArgs) -> ProgramResult {
+    if uses_config.total == 0 {
+        return Err(MetaplexError::InvalidUseConfig.into());
+    }
+
+    if uses_config.use_method == UseMethod::Single && uses_config.total != 1 {
+        return Err(MetaplexError::InvalidUseConfig.into());
+    }
+
+    Ok(())
+}
+
+/// The edition minted off a master inherits that master's update authority, not the auction
+/// manager PDA that's signing these CPIs. The auction manager only ends up holding that
+/// authority because an earlier step (outside this processor, when the safety deposit box was
+/// validated into the auction) hands it over; this just turns a violation of that invariant into
+/// an explicit error instead of letting `update_metadata_accounts_v2` fail opaquely on-chain.
+fn assert_auction_manager_is_update_authority(
+    metadata_account_info: &AccountInfo,
+    auction_manager_info: &AccountInfo,
+) -> ProgramResult {
+    let metadata = Metadata::from_account_info(metadata_account_info)?;
+
+    if metadata.update_authority != *auction_manager_info.key {
+        return Err(MetaplexError::AuctionManagerNotUpdateAuthority.into());
+    }
+
+    Ok(())
+}
+
+/// Initializes the freshly minted edition's `Uses` record so it can be burned or redeemed a
+/// fixed number of times, per the `uses_config` configured on this prize's `WinningConfigItem`.
+/// `remaining` always starts equal to `total` since this is the edition's very first use.
+fn initialize_edition_uses<'a>(
+    token_metadata_program_info: &AccountInfo<'a>,
+    new_metadata_account_info: &AccountInfo<'a>,
+    auction_manager_info: &AccountInfo<'a>,
+    uses_config: &UsesConfigArgs,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    assert_valid_use_config(uses_config)?;
+
+    invoke_signed(
+        &update_metadata_accounts_v2(
+            *token_metadata_program_info.key,
+            *new_metadata_account_info.key,
+            *auction_manager_info.key,
+            None,
+            None,
+            None,
+            None,
+            Some(Some(Uses {
+                use_method: uses_config.use_method,
+                total: uses_config.total,
+                remaining: uses_config.total,
+            })),
+        ),
+        &[
+            new_metadata_account_info.clone(),
+            auction_manager_info.clone(),
+        ],
+        &[&signer_seeds],
+    )
+}
+
+/// CPI into token-metadata's verify-collection instruction, using the auction manager PDA
+/// as the collection authority that the collection's update authority must have delegated
+/// to (directly, or via a collection authority record). When `collection_authority_record_info`
+/// is `Some`, it's both passed to the instruction builder (so the resulting `AccountMeta`s
+/// include it) and appended to the account list handed to `invoke_signed`.
+#[allow(clippy::too_many_arguments)]
+fn verify_edition_collection<'a>(
+    token_metadata_program_info: &AccountInfo<'a>,
+    new_metadata_account_info: &AccountInfo<'a>,
+    auction_manager_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    collection_mint_info: &AccountInfo<'a>,
+    collection_metadata_info: &AccountInfo<'a>,
+    collection_master_edition_info: &AccountInfo<'a>,
+    collection_authority_record_info: Option<&AccountInfo<'a>>,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let mut account_infos = vec![
+        new_metadata_account_info.clone(),
+        auction_manager_info.clone(),
+        payer_info.clone(),
+        collection_mint_info.clone(),
+        collection_metadata_info.clone(),
+        collection_master_edition_info.clone(),
+    ];
+
+    let collection_authority_record = collection_authority_record_info.map(|info| {
+        account_infos.push(info.clone());
+        *info.key
+    });
+
+    invoke_signed(
+        &verify_collection(
+            *token_metadata_program_info.key,
+            *new_metadata_account_info.key,
+            *auction_manager_info.key,
+            *payer_info.key,
+            *collection_mint_info.key,
+            *collection_metadata_info.key,
+            *collection_master_edition_info.key,
+            collection_authority_record,
+        ),
+        &account_infos,
+        &[&signer_seeds],
+    )
+}
+
 fn count_item_amount_by_safety_deposit_order(
     items: &Vec<WinningConfigItem>,
     safety_deposit_index: u8,
@@ -64,8 +261,12 @@ pub fn mint_edition<'a>(
     token_program_info: &AccountInfo<'a>,
     system_program_info: &AccountInfo<'a>,
     rent_info: &AccountInfo<'a>,
+    prize_tracking_ticket_info: &AccountInfo<'a>,
     actual_edition: u64,
     signer_seeds: &[&[u8]],
+    collection: Option<CollectionVerifyArgs<'_, 'a>>,
+    token_metadata_program: &Pubkey,
+    uses_config: &Option<UsesConfigArgs>,
 ) -> ProgramResult {
     invoke_signed(
         &mint_edition_from_master_edition_via_vault_proxy(
@@ -110,10 +311,129 @@ pub fn mint_edition<'a>(
         &[&signer_seeds],
     )?;
 
+    if let Some(uses_config) = uses_config {
+        assert_auction_manager_is_update_authority(metadata_account_info, auction_manager_info)?;
+
+        initialize_edition_uses(
+            token_metadata_program_info,
+            new_metadata_account_info,
+            auction_manager_info,
+            uses_config,
+            signer_seeds,
+        )?;
+    }
+
+    if let Some(CollectionVerifyArgs {
+        collection_metadata_info,
+        collection_mint_info,
+        collection_master_edition_info,
+        collection_authority_record_info,
+    }) = collection
+    {
+        assert_collection_verify_is_valid(
+            collection_metadata_info,
+            collection_mint_info,
+            collection_master_edition_info,
+            auction_manager_info,
+            collection_authority_record_info,
+            token_metadata_program,
+        )?;
+
+        verify_edition_collection(
+            token_metadata_program_info,
+            new_metadata_account_info,
+            auction_manager_info,
+            payer_info,
+            collection_mint_info,
+            collection_metadata_info,
+            collection_master_edition_info,
+            collection_authority_record_info,
+            signer_seeds,
+        )?;
+
+        let mut ticket = PrizeTrackingTicket::from_account_info(prize_tracking_ticket_info)?;
+        ticket.collection_verified = true;
+        ticket.save(prize_tracking_ticket_info)?;
+    }
+
     Ok(())
 }
 
-pub fn create_or_update_prize_tracking<'a>(
+/// In-memory view of a `PrizeTrackingTicket` account, read and written without borsh (CPU is
+/// precious in this large action) but no longer pinned to `V1`'s fixed offsets. `V1` tickets
+/// (`key`/`metadata`/`supply_snapshot`/`expected_redemptions`/`redemptions`, then 50 bytes of
+/// untouched padding) are upgraded to `V2` the first time they're saved, which claims one byte
+/// of that padding for `collection_verified` and leaves the remaining 49 reserved for whatever
+/// comes next. Both versions fit in `MAX_PRIZE_TRACKING_TICKET_SIZE`, so no resize is needed.
+pub struct PrizeTrackingTicket {
+    pub metadata: Pubkey,
+    pub supply_snapshot: u64,
+    pub expected_redemptions: u64,
+    pub redemptions: u64,
+    pub collection_verified: bool,
+}
+
+impl PrizeTrackingTicket {
+    fn new(metadata: Pubkey, supply_snapshot: u64, expected_redemptions: u64) -> Self {
+        Self {
+            metadata,
+            supply_snapshot,
+            expected_redemptions,
+            redemptions: 0,
+            collection_verified: false,
+        }
+    }
+
+    /// Reads whichever layout is on disk. `V1` tickets have no `collection_verified` byte, so
+    /// it defaults to `false` until the ticket is next saved and upgraded to `V2`.
+    fn from_account_info(info: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = info.data.borrow();
+        let input = array_ref![data, 0, MAX_PRIZE_TRACKING_TICKET_SIZE];
+        let (key, metadata, supply_snapshot, expected_redemptions, redemptions, padding) =
+            arrayref::array_refs![input, 1, 32, 8, 8, 8, 50];
+
+        match key[0] {
+            k if k == Key::PrizeTrackingTicketV1 as u8 => Ok(Self {
+                metadata: Pubkey::new_from_array(*metadata),
+                supply_snapshot: u64::from_le_bytes(*supply_snapshot),
+                expected_redemptions: u64::from_le_bytes(*expected_redemptions),
+                redemptions: u64::from_le_bytes(*redemptions),
+                collection_verified: false,
+            }),
+            k if k == Key::PrizeTrackingTicketV2 as u8 => Ok(Self {
+                metadata: Pubkey::new_from_array(*metadata),
+                supply_snapshot: u64::from_le_bytes(*supply_snapshot),
+                expected_redemptions: u64::from_le_bytes(*expected_redemptions),
+                redemptions: u64::from_le_bytes(*redemptions),
+                collection_verified: padding[0] != 0,
+            }),
+            _ => Err(MetaplexError::DataTypeMismatch.into()),
+        }
+    }
+
+    /// Always writes the `V2` layout, lazily upgrading a `V1` ticket in place.
+    fn save(&self, info: &AccountInfo) -> ProgramResult {
+        let mut data = info.data.borrow_mut();
+        let output = array_mut_ref![data, 0, MAX_PRIZE_TRACKING_TICKET_SIZE];
+        let (key, metadata, supply_snapshot, expected_redemptions, redemptions, padding) =
+            mut_array_refs![output, 1, 32, 8, 8, 8, 50];
+
+        *key = [Key::PrizeTrackingTicketV2 as u8];
+        metadata.copy_from_slice(self.metadata.as_ref());
+        *supply_snapshot = self.supply_snapshot.to_le_bytes();
+        *expected_redemptions = self.expected_redemptions.to_le_bytes();
+        *redemptions = self.redemptions.to_le_bytes();
+        padding[0] = self.collection_verified as u8;
+
+        Ok(())
+    }
+}
+
+/// Creates a prize's tracking ticket and locks in its `supply_snapshot`, if one doesn't already
+/// exist. Idempotent: called more than once (e.g. because validation ran twice, or ran after a
+/// redemption already created the ticket under the old lazy-create behavior) it just returns the
+/// snapshot that's already there instead of re-reading the master edition's current supply.
+fn initialize_prize_tracking<'a>(
     program_id: &'a Pubkey,
     auction_manager_info: &AccountInfo<'a>,
     prize_tracking_ticket_info: &AccountInfo<'a>,
@@ -126,6 +446,7 @@ pub fn create_or_update_prize_tracking<'a>(
 ) -> Result<u64, ProgramError> {
     let metadata_data = metadata_account_info.data.borrow();
     let metadata_mint = Pubkey::new_from_array(*array_ref![metadata_data, 33, 32]);
+    drop(metadata_data);
 
     let bump = assert_derivation(
         program_id,
@@ -138,7 +459,6 @@ pub fn create_or_update_prize_tracking<'a>(
         ],
     )?;
 
-    let supply_snapshot: u64;
     if prize_tracking_ticket_info.data_is_empty() {
         create_or_allocate_account_raw(
             *program_id,
@@ -155,35 +475,81 @@ pub fn create_or_update_prize_tracking<'a>(
                 &[bump],
             ],
         )?;
-        let data = &mut prize_tracking_ticket_info.data.borrow_mut();
-        let output = array_mut_ref![data, 0, MAX_PRIZE_TRACKING_TICKET_SIZE];
 
-        let (key, metadata, supply_snapshot_ptr, expected_redemptions_ptr, redemptions, _padding) =
-            mut_array_refs![output, 1, 32, 8, 8, 8, 50];
+        let supply_snapshot = get_supply_off_master_edition(master_edition_account_info)?;
+        let ticket =
+            PrizeTrackingTicket::new(*metadata_account_info.key, supply_snapshot, expected_redemptions);
+        ticket.save(prize_tracking_ticket_info)?;
 
-        *key = [Key::PrizeTrackingTicketV1 as u8];
-        metadata.copy_from_slice(metadata_account_info.key.as_ref());
-        supply_snapshot = get_supply_off_master_edition(master_edition_account_info)?;
-        *supply_snapshot_ptr = supply_snapshot.to_le_bytes();
-        *redemptions = 1u64.to_le_bytes();
-        *expected_redemptions_ptr = expected_redemptions.to_le_bytes();
+        Ok(supply_snapshot)
     } else {
-        // CPU is very precious in this large action, so we skip borsh's angry CPU usage.
-        let data = &mut prize_tracking_ticket_info.data.borrow_mut();
-        let output = array_mut_ref![data, 0, MAX_PRIZE_TRACKING_TICKET_SIZE];
+        let ticket = PrizeTrackingTicket::from_account_info(prize_tracking_ticket_info)?;
 
-        let (_key, _metadata, supply_snapshot_ptr, _expected_redemptions, redemptions, _padding) =
-            mut_array_refs![output, 1, 32, 8, 8, 8, 50];
-        supply_snapshot = u64::from_le_bytes(*supply_snapshot_ptr);
-        let next_redemptions = u64::from_le_bytes(*redemptions)
-            .checked_add(1)
-            .ok_or(MetaplexError::NumericalOverflowError)?;
-        *redemptions = next_redemptions.to_le_bytes();
+        Ok(ticket.supply_snapshot)
     }
+}
+
+/// Increments a prize's redemption count and returns its locked-in `supply_snapshot`. The ticket
+/// must already have been created by `process_validate_printing_v2_prize`; redemption never
+/// creates one itself, since lazily creating it on whichever redemption happens to arrive first
+/// is exactly the order-dependence that instruction exists to avoid.
+fn record_prize_redemption(
+    prize_tracking_ticket_info: &AccountInfo,
+    redemption_count: u64,
+) -> Result<u64, ProgramError> {
+    if prize_tracking_ticket_info.data_is_empty() {
+        return Err(MetaplexError::PrizeNotValidated.into());
+    }
+
+    let mut ticket = PrizeTrackingTicket::from_account_info(prize_tracking_ticket_info)?;
+    ticket.redemptions = ticket
+        .redemptions
+        .checked_add(redemption_count)
+        .ok_or(MetaplexError::NumericalOverflowError)?;
+    let supply_snapshot = ticket.supply_snapshot;
+    ticket.save(prize_tracking_ticket_info)?;
 
     Ok(supply_snapshot)
 }
 
+/// Snapshots a PrintingV2 prize's master edition supply once, ahead of any redemption, and locks
+/// it into the prize's tracking ticket. Meant to run when the safety deposit box backing this
+/// prize is validated into the auction, before bidding opens: every winner who shares this
+/// metadata with other winning config items then computes `actual_edition` off the same
+/// snapshot, no matter which of them (or which other prize sharing this master edition) is
+/// redeemed first.
+pub fn process_validate_printing_v2_prize<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    expected_redemptions: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let auction_manager_info = next_account_info(account_info_iter)?;
+    let metadata_account_info = next_account_info(account_info_iter)?;
+    let master_edition_account_info = next_account_info(account_info_iter)?;
+    let prize_tracking_ticket_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+
+    assert_owned_by(auction_manager_info, program_id)?;
+
+    initialize_prize_tracking(
+        program_id,
+        auction_manager_info,
+        prize_tracking_ticket_info,
+        metadata_account_info,
+        payer_info,
+        rent_info,
+        system_info,
+        master_edition_account_info,
+        expected_redemptions,
+    )?;
+
+    Ok(())
+}
+
 pub fn process_redeem_printing_v2_bid<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
@@ -219,6 +585,15 @@ pub fn process_redeem_printing_v2_bid<'a>(
     let mint_authority_info = next_account_info(account_info_iter)?;
     let metadata_account_info = next_account_info(account_info_iter)?;
 
+    // The first three are optional and only present when the caller wants the freshly minted
+    // edition verified into a collection; all or none must be supplied. The fourth is only
+    // needed on top of those three when the auction manager isn't the collection's update
+    // authority directly and instead holds a delegated `CollectionAuthorityRecord`.
+    let collection_mint_info = next_account_info(account_info_iter).ok();
+    let collection_metadata_info = next_account_info(account_info_iter).ok();
+    let collection_master_edition_info = next_account_info(account_info_iter).ok();
+    let collection_authority_record_info = next_account_info(account_info_iter).ok();
+
     let new_edition_account_amount = get_amount_from_token_account(new_edition_token_account_info)?;
 
     assert_is_ata(
@@ -291,26 +666,14 @@ pub fn process_redeem_printing_v2_bid<'a>(
                 let safety_deposit_box_order = SafetyDepositBox::get_order(safety_deposit_info);
 
                 let mut edition_offset_min: u64 = 1;
-                let mut expected_redemptions: u64 = 0;
 
                 // Given every single winning config item carries a u8, it is impossible to overflow
                 // a u64 with the amount in it given the limited size. Avoid using checked add to save on cpu.
-                for n in 0..auction_manager.settings.winning_configs.len() {
-                    let matching = count_item_amount_by_safety_deposit_order(
+                for n in 0..winning_index {
+                    edition_offset_min += count_item_amount_by_safety_deposit_order(
                         &auction_manager.settings.winning_configs[n].items,
                         safety_deposit_box_order,
                     );
-
-                    if n < winning_index {
-                        edition_offset_min += matching
-                    }
-                    if prize_tracking_ticket_info.data_is_empty() {
-                        expected_redemptions += matching
-                    } else if n >= winning_index {
-                        // no need to keep using this loop more than winning_index if we're not
-                        // tabulating expected_redemptions
-                        break;
-                    }
                 }
 
                 let edition_offset_max = edition_offset_min
@@ -323,17 +686,7 @@ pub fn process_redeem_printing_v2_bid<'a>(
                     return Err(MetaplexError::InvalidEditionNumber.into());
                 }
 
-                let supply_snapshot = create_or_update_prize_tracking(
-                    program_id,
-                    auction_manager_info,
-                    prize_tracking_ticket_info,
-                    metadata_account_info,
-                    payer_info,
-                    rent_info,
-                    system_info,
-                    master_edition_account_info,
-                    expected_redemptions,
-                )?;
+                let supply_snapshot = record_prize_redemption(prize_tracking_ticket_info, 1)?;
 
                 let actual_edition = edition_offset
                     .checked_add(supply_snapshot)
@@ -345,6 +698,23 @@ pub fn process_redeem_printing_v2_bid<'a>(
                     &[auction_manager_bump],
                 ];
 
+                let collection = match (
+                    collection_mint_info,
+                    collection_metadata_info,
+                    collection_master_edition_info,
+                ) {
+                    (None, None, None) => None,
+                    (Some(collection_mint_info), Some(collection_metadata_info), Some(collection_master_edition_info)) => {
+                        Some(CollectionVerifyArgs {
+                            collection_metadata_info,
+                            collection_mint_info,
+                            collection_master_edition_info,
+                            collection_authority_record_info,
+                        })
+                    }
+                    _ => return Err(MetaplexError::IncompleteCollectionAccounts.into()),
+                };
+
                 mint_edition(
                     token_metadata_program_info,
                     token_vault_program_info,
@@ -364,8 +734,12 @@ pub fn process_redeem_printing_v2_bid<'a>(
                     token_program_info,
                     system_info,
                     rent_info,
+                    prize_tracking_ticket_info,
                     actual_edition,
                     signer_seeds,
+                    collection,
+                    &token_metadata_program,
+                    &winning_config_item.uses_config,
                 )?;
             }
         }
@@ -390,3 +764,265 @@ pub fn process_redeem_printing_v2_bid<'a>(
 
     Ok(())
 }
+
+/// One edition's worth of per-edition accounts in a batch redemption. Every edition in the
+/// requested range needs its own mint, metadata, master edition-derived printing edition and
+/// destination token account, but editions that fall in the same `EDITION_MARKER_BIT_SIZE`
+/// window share a single edition marker account.
+pub struct BatchEditionAccounts<'a> {
+    pub new_metadata_account_info: &'a AccountInfo<'a>,
+    pub new_edition_account_info: &'a AccountInfo<'a>,
+    pub new_edition_token_account_info: &'a AccountInfo<'a>,
+    pub mint_info: &'a AccountInfo<'a>,
+}
+
+/// Mints every edition in `[edition_offset_start, edition_offset_start + count)` for a single
+/// winning config item in one instruction. This exists because `process_redeem_printing_v2_bid`
+/// hard-requires exactly one edition per call, which is wasteful for winners of large PrintingV2
+/// prizes: every transaction repeats the same account loading and auction manager validation.
+///
+/// Beyond the fixed accounts shared with the single-edition path, the account slice carries,
+/// back to back: `count` groups of 4 per-edition accounts (`new_metadata_account_info`,
+/// `new_edition_account_info`, `new_edition_token_account_info`, `mint_info`, in that order),
+/// then one edition marker account for every distinct `actual_edition / EDITION_MARKER_BIT_SIZE`
+/// window the requested range touches, ordered by increasing marker index. A marker covering
+/// several of the requested editions is only passed (and only paid for) once instead of once
+/// per edition.
+#[allow(clippy::too_many_arguments)]
+pub fn process_redeem_printing_v2_bid_batch<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    edition_offset_start: u64,
+    count: u64,
+    user_provided_win_index: u64,
+) -> ProgramResult {
+    if count == 0 {
+        return Err(MetaplexError::InvalidEditionNumber.into());
+    }
+
+    let account_info_iter = &mut accounts.iter();
+
+    let auction_manager_info = next_account_info(account_info_iter)?;
+    let safety_deposit_token_store_info = next_account_info(account_info_iter)?;
+    let bid_redemption_info = next_account_info(account_info_iter)?;
+    let safety_deposit_info = next_account_info(account_info_iter)?;
+    let vault_info = next_account_info(account_info_iter)?;
+    let _fraction_mint_info = next_account_info(account_info_iter)?;
+    let auction_info = next_account_info(account_info_iter)?;
+    let bidder_metadata_info = next_account_info(account_info_iter)?;
+    let bidder_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let token_program_info = next_account_info(account_info_iter)?;
+    let token_vault_program_info = next_account_info(account_info_iter)?;
+    let token_metadata_program_info = next_account_info(account_info_iter)?;
+    let store_info = next_account_info(account_info_iter)?;
+    let system_info = next_account_info(account_info_iter)?;
+    let rent_info = next_account_info(account_info_iter)?;
+
+    let prize_tracking_ticket_info = next_account_info(account_info_iter)?;
+    let master_edition_account_info = next_account_info(account_info_iter)?;
+    let mint_authority_info = next_account_info(account_info_iter)?;
+    let metadata_account_info = next_account_info(account_info_iter)?;
+
+    let editions = (0..count)
+        .map(|_| {
+            Ok(BatchEditionAccounts {
+                new_metadata_account_info: next_account_info(account_info_iter)?,
+                new_edition_account_info: next_account_info(account_info_iter)?,
+                new_edition_token_account_info: next_account_info(account_info_iter)?,
+                mint_info: next_account_info(account_info_iter)?,
+            })
+        })
+        .collect::<Result<Vec<BatchEditionAccounts>, ProgramError>>()?;
+
+    // common_redeem_checks is keyed on the first requested edition's destination token account;
+    // ownership of the remaining destination accounts is asserted per-edition below.
+    let CommonRedeemReturn {
+        auction_manager,
+        redemption_bump_seed,
+        cancelled,
+        auction: _a,
+        rent: _rent,
+        win_index,
+        token_metadata_program,
+    } = common_redeem_checks(CommonRedeemCheckArgs {
+        program_id,
+        auction_manager_info,
+        safety_deposit_token_store_info,
+        destination_info: editions[0].new_edition_token_account_info,
+        bid_redemption_info,
+        safety_deposit_info,
+        vault_info,
+        auction_info,
+        bidder_metadata_info,
+        bidder_info,
+        token_program_info,
+        token_vault_program_info,
+        token_metadata_program_info,
+        store_info,
+        rent_info,
+        is_participation: false,
+        user_provided_win_index: Some(Some(user_provided_win_index as usize)),
+        overwrite_win_index: None,
+        assert_bidder_signer: false,
+    })?;
+
+    assert_owned_by(metadata_account_info, &token_metadata_program)?;
+
+    if cancelled {
+        return common_redeem_finish(CommonRedeemFinishArgs {
+            program_id,
+            auction_manager,
+            auction_manager_info,
+            bidder_metadata_info,
+            rent_info,
+            system_info,
+            payer_info,
+            bid_redemption_info,
+            redemption_bump_seed,
+            winning_index: win_index,
+            bid_redeemed: true,
+            participation_redeemed: false,
+            winning_item_index: None,
+            overwrite_win_index: None,
+        });
+    }
+
+    let winning_index = win_index.ok_or(MetaplexError::InvalidEditionNumber)?;
+    if winning_index >= auction_manager.settings.winning_configs.len() {
+        return Err(MetaplexError::InvalidEditionNumber.into());
+    }
+
+    let CommonWinningConfigCheckReturn {
+        winning_config_item,
+        winning_item_index,
+    } = common_winning_config_checks(&auction_manager, &safety_deposit_info, winning_index)?;
+
+    if winning_config_item.winning_config_type != WinningConfigType::PrintingV2 {
+        return Err(MetaplexError::WrongBidEndpointForPrize.into());
+    }
+
+    let auction_manager_bump = assert_derivation(
+        program_id,
+        auction_manager_info,
+        &[PREFIX.as_bytes(), auction_info.key.as_ref()],
+    )?;
+
+    let safety_deposit_box_order = SafetyDepositBox::get_order(safety_deposit_info);
+
+    let mut edition_offset_min: u64 = 1;
+
+    for n in 0..winning_index {
+        edition_offset_min += count_item_amount_by_safety_deposit_order(
+            &auction_manager.settings.winning_configs[n].items,
+            safety_deposit_box_order,
+        );
+    }
+
+    let edition_offset_max = edition_offset_min
+        + count_item_amount_by_safety_deposit_order(
+            &auction_manager.settings.winning_configs[winning_index].items,
+            safety_deposit_box_order,
+        );
+
+    let edition_offset_end = edition_offset_start
+        .checked_add(count)
+        .ok_or(MetaplexError::NumericalOverflowError)?;
+
+    if edition_offset_start < edition_offset_min || edition_offset_end > edition_offset_max {
+        return Err(MetaplexError::InvalidEditionNumber.into());
+    }
+
+    let supply_snapshot = record_prize_redemption(prize_tracking_ticket_info, count)?;
+
+    let first_actual_edition = edition_offset_start
+        .checked_add(supply_snapshot)
+        .ok_or(MetaplexError::NumericalOverflowError)?;
+    let last_actual_edition = edition_offset_end
+        .checked_add(supply_snapshot)
+        .ok_or(MetaplexError::NumericalOverflowError)?
+        .checked_sub(1)
+        .ok_or(MetaplexError::NumericalOverflowError)?;
+
+    let first_marker_index = first_actual_edition / EDITION_MARKER_BIT_SIZE as u64;
+    let last_marker_index = last_actual_edition / EDITION_MARKER_BIT_SIZE as u64;
+
+    let edition_markers = (first_marker_index..=last_marker_index)
+        .map(|marker_index| Ok((marker_index, next_account_info(account_info_iter)?)))
+        .collect::<Result<BTreeMap<u64, &AccountInfo>, ProgramError>>()?;
+
+    let signer_seeds = &[
+        PREFIX.as_bytes(),
+        auction_info.key.as_ref(),
+        &[auction_manager_bump],
+    ];
+
+    for (i, edition) in editions.iter().enumerate() {
+        let edition_offset = edition_offset_start + i as u64;
+        let actual_edition = edition_offset
+            .checked_add(supply_snapshot)
+            .ok_or(MetaplexError::NumericalOverflowError)?;
+
+        assert_is_ata(
+            edition.new_edition_token_account_info,
+            bidder_info.key,
+            token_program_info.key,
+            edition.mint_info.key,
+        )?;
+
+        if get_amount_from_token_account(edition.new_edition_token_account_info)? != 1 {
+            return Err(MetaplexError::ProvidedAccountDoesNotContainOneToken.into());
+        }
+
+        let marker_index = actual_edition / EDITION_MARKER_BIT_SIZE as u64;
+        let edition_marker_info = edition_markers
+            .get(&marker_index)
+            .ok_or(MetaplexError::InvalidEditionNumber)?;
+
+        mint_edition(
+            token_metadata_program_info,
+            token_vault_program_info,
+            edition.new_metadata_account_info,
+            edition.new_edition_account_info,
+            master_edition_account_info,
+            edition_marker_info,
+            edition.mint_info,
+            mint_authority_info,
+            payer_info,
+            auction_manager_info,
+            safety_deposit_token_store_info,
+            safety_deposit_info,
+            vault_info,
+            bidder_info,
+            metadata_account_info,
+            token_program_info,
+            system_info,
+            rent_info,
+            prize_tracking_ticket_info,
+            actual_edition,
+            signer_seeds,
+            None,
+            &token_metadata_program,
+            &winning_config_item.uses_config,
+        )?;
+    }
+
+    common_redeem_finish(CommonRedeemFinishArgs {
+        program_id,
+        auction_manager,
+        auction_manager_info,
+        bidder_metadata_info,
+        rent_info,
+        system_info,
+        payer_info,
+        bid_redemption_info,
+        redemption_bump_seed,
+        winning_index: win_index,
+        bid_redeemed: true,
+        participation_redeemed: false,
+        winning_item_index,
+        overwrite_win_index: None,
+    })?;
+
+    Ok(())
+}
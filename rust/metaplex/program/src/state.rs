@@ -0,0 +1,91 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    spl_token_metadata::state::UseMethod,
+};
+
+/// Prefix used to derive most of the program's PDAs.
+pub const PREFIX: &str = "metaplex";
+
+/// `key(1) + metadata(32) + supply_snapshot(8) + expected_redemptions(8) + redemptions(8) +
+/// padding(50)`, large enough to hold every version of `PrizeTrackingTicket` without a resize.
+pub const MAX_PRIZE_TRACKING_TICKET_SIZE: usize = 1 + 32 + 8 + 8 + 8 + 50;
+
+/// Tag byte stored as the first byte of every Metaplex-owned account, identifying both the
+/// account's type and its on-disk layout version. New variants are only ever appended at the
+/// end so that an old tag byte never gets reinterpreted as a newer layout.
+#[derive(Clone, Debug, Eq, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum Key {
+    Uninitialized,
+    OriginalAuthorityLookupV1,
+    BidRedemptionTicketV1,
+    StoreV1,
+    WhitelistedCreatorV1,
+    PayoutTicketV1,
+    SafetyDepositValidationTicketV1,
+    AuctionManagerV1,
+    PrizeTrackingTicketV1,
+    SafetyDepositConfigV1,
+    AuctionManagerV2,
+    BidRedemptionTicketV2,
+    AuctionWinnerTokenTypeTrackerV1,
+    PrizeTrackingTicketV2,
+}
+
+/// The kind of prize a `WinningConfigItem` represents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum WinningConfigType {
+    /// You will get the original NFT.
+    TokenOnlyTransfer,
+    /// You will get a printing for the original NFT.
+    PrintingV1,
+    /// You will get a printing for the original NFT, using the newer PrintingV2 mechanism.
+    PrintingV2,
+    /// You are getting a participation prize.
+    Participation,
+}
+
+/// Mirrors token-metadata's `UsesConfig` minting argument: how many times a prize can be used,
+/// and what happens on each use.
+#[derive(Clone, Copy, Debug, BorshSerialize, BorshDeserialize)]
+pub struct UsesConfigArgs {
+    pub use_method: UseMethod,
+    pub total: u64,
+}
+
+/// One prize, carved out of a single safety deposit box, within a `WinningConfig`.
+///
+/// `uses_config` was appended after this struct was already being serialized on-chain. A
+/// hand-written `BorshDeserialize` that treated an empty remaining buffer as "no uses configured"
+/// was tried here, but it doesn't work: items are always read as `Vec<WinningConfigItem>`
+/// (`WinningConfig` -> `AuctionManagerSettings` -> `AuctionManager`), and borsh hands every
+/// element of a `Vec` the *entire* remaining buffer, not a length-delimited slice of its own. The
+/// buffer is only actually empty for the last item of the last config of the last vector in the
+/// whole account — every other item mid-list would silently consume the *next* item's bytes as
+/// its own `uses_config`. There's no per-item signal this struct can see that distinguishes
+/// "field omitted" from "field present"; that can only come from a version tag above it in
+/// `AuctionManager`, which this struct has no access to. So `uses_config` is required: accounts
+/// serialized before this field existed need an explicit one-time migration before they can be
+/// parsed again, rather than an unreliable guess at load time.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct WinningConfigItem {
+    pub safety_deposit_box_index: u8,
+    pub amount: u8,
+    pub winning_config_type: WinningConfigType,
+    pub uses_config: Option<UsesConfigArgs>,
+}
+
+/// All the prizes a single winning place is entitled to.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct WinningConfig {
+    pub items: Vec<WinningConfigItem>,
+}
+
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct AuctionManagerSettings {
+    pub winning_configs: Vec<WinningConfig>,
+}
+
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct AuctionManager {
+    pub settings: AuctionManagerSettings,
+}